@@ -1,44 +1,218 @@
 use std::{fs, io};
+use std::io::Read;
 use std::path::{Path, PathBuf};
 use anyhow::Context;
 use chrono::Utc;
+use filetime::FileTime;
+use rayon::prelude::*;
+
+// Controls how individual files are copied by `copy_dir_recursively`.
+#[derive(Clone, Copy)]
+struct CopyOptions {
+    // Overwrite a destination file that already differs from the source. When false, an
+    // existing destination file is left untouched.
+    overwrite: bool,
+    // Skip rewriting a destination file that is byte-for-byte identical to the source, which
+    // matters when `dst` was seeded from a previous backup (see `ScummedProfile::scumm_profile`).
+    skip_identical: bool,
+    // Chunk size used when comparing files for `skip_identical`.
+    buffer_size: usize,
+    // Copy the source file's modification time onto the destination.
+    preserve_times: bool,
+}
+
+impl Default for CopyOptions {
+    fn default() -> Self {
+        CopyOptions {
+            overwrite: true,
+            skip_identical: true,
+            buffer_size: 64 * 1024,
+            preserve_times: true,
+        }
+    }
+}
+
+fn files_identical(a: &Path, b: &Path, buffer_size: usize) -> io::Result<bool> {
+    let (meta_a, meta_b) = (fs::metadata(a)?, fs::metadata(b)?);
+    if meta_a.len() != meta_b.len() {
+        return Ok(false);
+    }
+
+    let (mut file_a, mut file_b) = (fs::File::open(a)?, fs::File::open(b)?);
+    let (mut buf_a, mut buf_b) = (vec![0u8; buffer_size], vec![0u8; buffer_size]);
+    loop {
+        let read_a = file_a.read(&mut buf_a)?;
+        let read_b = file_b.read(&mut buf_b)?;
+        if read_a != read_b || buf_a[..read_a] != buf_b[..read_b] {
+            return Ok(false);
+        }
+        if read_a == 0 {
+            return Ok(true);
+        }
+    }
+}
+
+fn copy_file_with_options(src: &Path, dst: &Path, options: &CopyOptions) -> anyhow::Result<()> {
+    if dst.exists() {
+        if options.skip_identical && files_identical(src, dst, options.buffer_size).context(
+            format!("Failed comparing {:?} and {:?}", src, dst)
+        )? {
+            return Ok(());
+        }
+        if !options.overwrite {
+            return Ok(());
+        }
+        fs::remove_file(dst).context(format!("Failed to remove stale dst file: {:?}", dst))?;
+    }
+
+    fs::copy(src, dst).context(format!("Failed trying to copy from {:?} to {:?}", src, dst))?;
+
+    if options.preserve_times {
+        let src_metadata = fs::metadata(src).context(format!("Failed to stat {:?}", src))?;
+        let mtime = FileTime::from_last_modification_time(&src_metadata);
+        let atime = FileTime::from_last_access_time(&src_metadata);
+        filetime::set_file_times(dst, atime, mtime).context(
+            format!("Failed to set file times on {:?}", dst)
+        )?;
+    }
+    Ok(())
+}
+
+// Removes entries under `dst` that have no counterpart in `src_names`, so a `dst` seeded from a
+// prior backup (see `hard_link_dir_recursively`) doesn't keep resurrecting files/dirs the player
+// has since deleted from the live profile.
+fn remove_stale_dst_entries(
+    dst: &Path,
+    src_names: &std::collections::HashSet<std::ffi::OsString>,
+) -> anyhow::Result<()> {
+    for entry in fs::read_dir(dst).context(format!("failed to read dst dir: {:?}", dst))? {
+        let entry = entry?;
+        if src_names.contains(&entry.file_name()) {
+            continue;
+        }
+        if entry.file_type()?.is_dir() {
+            fs::remove_dir_all(entry.path()).context(
+                format!("Failed to remove stale dst dir {:?}", entry.path())
+            )?;
+        } else {
+            fs::remove_file(entry.path()).context(
+                format!("Failed to remove stale dst file {:?}", entry.path())
+            )?;
+        }
+    }
+    Ok(())
+}
 
 // Adapted from
 // https://stackoverflow.com/questions/26958489/how-to-copy-a-folder-recursively-in-rust
-fn copy_dir_recursively(src: &Path, dst: &Path) -> anyhow::Result<()> {
+//
+// Recursive rather than an explicit work-queue: profile trees are shallow enough that stack
+// depth isn't a concern, and entries within a directory (including subdirectory recursions) are
+// processed with rayon so large profiles copy in parallel.
+fn copy_dir_recursively(src: &Path, dst: &Path, options: &CopyOptions) -> anyhow::Result<()> {
     fs::create_dir_all(&dst).context(format!("failed to create dst dir: {:?}", dst))?;
+    let entries: Vec<fs::DirEntry> = fs::read_dir(src)
+        .context(format!("failed to read src dir: {:?}", src))?
+        .collect::<Result<Vec<_>, _>>()
+        .context(format!("failed to read entries of src dir: {:?}", src))?;
+
+    let src_names: std::collections::HashSet<std::ffi::OsString> = entries
+        .iter()
+        .map(|entry| entry.file_name())
+        .collect();
+    remove_stale_dst_entries(dst, &src_names)
+        .context(format!("Failed to reconcile stale entries in {:?}", dst))?;
+
+    let errors: Vec<anyhow::Error> = entries
+        .par_iter()
+        .filter_map(|entry| {
+            let dst_path = dst.join(entry.file_name());
+            let result = (|| -> anyhow::Result<()> {
+                if entry.file_type()?.is_dir() {
+                    copy_dir_recursively(&entry.path(), &dst_path, options)
+                } else {
+                    copy_file_with_options(&entry.path(), &dst_path, options)
+                }
+            })();
+            result.err().map(|e| e.context(
+                format!("Failed trying to copy from {:?} to {:?}", entry.path(), dst_path)
+            ))
+        })
+        .collect();
+
+    if !errors.is_empty() {
+        return Err(anyhow::anyhow!(
+            "failed to copy {} of {} entries in {:?}: {}",
+            errors.len(),
+            entries.len(),
+            src,
+            errors.iter().map(|e| e.to_string()).collect::<Vec<_>>().join("; "),
+        ));
+    }
+    Ok(())
+}
+
+// Hard-links `src`'s tree onto `dst`, used to cheaply seed a new backup from the most recent one
+// so `copy_dir_recursively` with `skip_identical` only has to materialize files that changed.
+fn hard_link_dir_recursively(src: &Path, dst: &Path) -> anyhow::Result<()> {
+    fs::create_dir_all(dst).context(format!("failed to create dst dir: {:?}", dst))?;
     for entry in fs::read_dir(src).context(format!("failed to read src dir: {:?}", src))? {
         let entry = entry?;
-        let ty = entry.file_type()?;
-        if ty.is_dir() {
-            copy_dir_recursively(&entry.path(), &dst.join(entry.file_name())).context(
-                format!("Failed trying to copy from {:?} to {:?}", entry.path(), dst.join(entry.file_name()))
-            )?;
+        let dst_path = dst.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            hard_link_dir_recursively(&entry.path(), &dst_path)?;
         } else {
-            fs::copy(entry.path(), &dst.join(entry.file_name())).context(
-                format!("Failed trying to copy from {:?} to {:?}", entry.path(), dst.join(entry.file_name()))
+            fs::hard_link(entry.path(), &dst_path).context(
+                format!("Failed trying to hard link from {:?} to {:?}", entry.path(), dst_path)
             )?;
         }
     }
     Ok(())
 }
 
-fn find_darkest_dungeon_2_app_data_dir() -> anyhow::Result<PathBuf> {
-    let username = whoami::username();
-    let expected_path = PathBuf::from(format!(
-        "C:/Users/{}/AppData/LocalLow/RedHook/Darkest Dungeon II", username
-    ));
+// Honored verbatim (after canonicalization) when set, to support non-standard installs.
+const APP_DIR_ENV_VAR: &str = "DD2_SCUMMER_APP_DIR";
+
+// `cli_override` (the `--app-dir` flag) takes precedence over `APP_DIR_ENV_VAR`, which in turn
+// takes precedence over OS-standard detection.
+fn find_darkest_dungeon_2_app_data_dir(cli_override: Option<&Path>) -> anyhow::Result<PathBuf> {
+    if let Some(cli_override) = cli_override {
+        return cli_override.canonicalize().context(
+            format!("Failed to canonicalize --app-dir {:?}", cli_override)
+        );
+    }
+    if let Ok(override_dir) = std::env::var(APP_DIR_ENV_VAR) {
+        let override_path = PathBuf::from(override_dir);
+        return override_path.canonicalize().context(
+            format!("Failed to canonicalize {}={:?}", APP_DIR_ENV_VAR, override_path)
+        );
+    }
+
+    let local_data_dir = dirs::data_local_dir().ok_or_else(|| anyhow::Error::new(io::Error::new(
+        io::ErrorKind::NotFound,
+        "could not determine OS local-data directory",
+    )))?;
+    // `LocalLow` is a sibling of the OS's standard (non-"low") local-data directory on Windows.
+    let local_low_dir = local_data_dir.parent().map(|parent| parent.join("LocalLow")).ok_or_else(
+        || anyhow::Error::new(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("could not determine LocalLow dir from {:?}", local_data_dir),
+        ))
+    )?;
+    let expected_path = local_low_dir.join("RedHook").join("Darkest Dungeon II");
     if !expected_path.exists() {
         return Err(anyhow::Error::new(io::Error::new(
             io::ErrorKind::NotFound,
             "Darkest Dungeon 2 app dir not found")
         ));
     }
-    Ok(expected_path)
+    expected_path.canonicalize().context(
+        format!("Failed to canonicalize {:?}", expected_path)
+    )
 }
 
-fn ensure_scumm_dir() -> anyhow::Result<PathBuf> {
-    let dd2_app_dir = match find_darkest_dungeon_2_app_data_dir() {
+fn ensure_scumm_dir(app_dir_override: Option<&Path>) -> anyhow::Result<PathBuf> {
+    let dd2_app_dir = match find_darkest_dungeon_2_app_data_dir(app_dir_override) {
         Err(e) => return Err(e.context("Failed to create scumm dir")),
         Ok(dir) => dir,
     };
@@ -58,8 +232,8 @@ fn ensure_scumm_dir() -> anyhow::Result<PathBuf> {
     Ok(scumm_dir)
 }
 
-fn find_save_dir() -> anyhow::Result<PathBuf> {
-    let app_dir = match find_darkest_dungeon_2_app_data_dir() {
+fn find_save_dir(app_dir_override: Option<&Path>) -> anyhow::Result<PathBuf> {
+    let app_dir = match find_darkest_dungeon_2_app_data_dir(app_dir_override) {
         Err(e) => return Err(e.context("Failed to find save dir")),
         Ok(app_dir) => app_dir,
     };
@@ -74,12 +248,12 @@ fn find_save_dir() -> anyhow::Result<PathBuf> {
     Ok(save_dir)
 }
 
-fn find_user_id_dirs() -> anyhow::Result<Vec<PathBuf>> {
+fn find_user_id_dirs(app_dir_override: Option<&Path>) -> anyhow::Result<Vec<PathBuf>> {
     // The interwebs suggest there should be only 1 sub dir corresponding to a user id.
     // https://www.pcgamingwiki.com/wiki/Darkest_Dungeon_II.
     // It seems possible if you had the game on both epic and steam you could end up with 2 (1 which
     // will be the steam ID, one the epic ID).
-    let save_dir = match find_save_dir() {
+    let save_dir = match find_save_dir(app_dir_override) {
         Err(e) => return Err(e.context("Failed to find user id dirs")),
         Ok(save_dir) => save_dir,
     };
@@ -101,26 +275,196 @@ fn find_user_id_dirs() -> anyhow::Result<Vec<PathBuf>> {
     Ok(sub_dirs)
 }
 
-fn find_profiles_dirs() -> anyhow::Result<Vec<PathBuf>> {
-    let user_id_dirs = find_user_id_dirs().context(
-        "Failed to find user id dirs while looking for profile dirs"
+fn profile_dir_from_user_id_dir(user_id_dir: &Path) -> anyhow::Result<PathBuf> {
+    let mut profiles_dir = user_id_dir.to_path_buf();
+    profiles_dir.push("profiles");
+    if !profiles_dir.exists() {
+        return Err(anyhow::Error::new(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("Profiles dir not found at {}", profiles_dir.to_str().expect(
+                "dir path should be a valid string"
+            )),
+        )));
+    }
+    Ok(profiles_dir)
+}
+
+// A minimal VDF (Valve Data Format) value, sufficient for reading `loginusers.vdf`.
+enum VdfValue {
+    Str(String),
+    Object(Vec<(String, VdfValue)>),
+}
+
+enum VdfToken {
+    Str(String),
+    Open,
+    Close,
+}
+
+fn tokenize_vdf(contents: &str) -> Vec<VdfToken> {
+    let mut tokens = Vec::new();
+    let mut chars = contents.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => {
+                let mut s = String::new();
+                while let Some(&next) = chars.peek() {
+                    if next == '"' {
+                        chars.next();
+                        break;
+                    }
+                    s.push(next);
+                    chars.next();
+                }
+                tokens.push(VdfToken::Str(s));
+            },
+            '{' => tokens.push(VdfToken::Open),
+            '}' => tokens.push(VdfToken::Close),
+            _ => (),
+        }
+    }
+    tokens
+}
+
+// Parses a brace-delimited run of `"key" "value"` / `"key" { ... }` pairs starting at `*pos`,
+// consuming the matching close brace (if any) before returning.
+fn parse_vdf_object(tokens: &[VdfToken], pos: &mut usize) -> Vec<(String, VdfValue)> {
+    let mut entries = Vec::new();
+    while *pos < tokens.len() {
+        match &tokens[*pos] {
+            VdfToken::Close => {
+                *pos += 1;
+                break;
+            },
+            VdfToken::Open => {
+                // Unexpected open brace with no preceding key; skip it.
+                *pos += 1;
+            },
+            VdfToken::Str(key) => {
+                let key = key.clone();
+                *pos += 1;
+                match tokens.get(*pos) {
+                    Some(VdfToken::Open) => {
+                        *pos += 1;
+                        let children = parse_vdf_object(tokens, pos);
+                        entries.push((key, VdfValue::Object(children)));
+                    },
+                    Some(VdfToken::Str(value)) => {
+                        entries.push((key, VdfValue::Str(value.clone())));
+                        *pos += 1;
+                    },
+                    _ => break,
+                }
+            },
+        }
+    }
+    entries
+}
+
+fn parse_vdf(contents: &str) -> Vec<(String, VdfValue)> {
+    let tokens = tokenize_vdf(contents);
+    let mut pos = 0;
+    parse_vdf_object(&tokens, &mut pos)
+}
+
+// Finds the 64-bit SteamID of the account flagged `MostRecent = "1"` in the `users` block of a
+// `loginusers.vdf`'s contents.
+fn most_recent_steam_id_from_vdf(contents: &str) -> Option<u64> {
+    let root = parse_vdf(contents);
+    let (_, users) = root.into_iter().find(|(key, _)| key.eq_ignore_ascii_case("users"))?;
+    let VdfValue::Object(users) = users else { return None };
+    for (steam_id_64, account) in users {
+        let VdfValue::Object(fields) = account else { continue };
+        let is_most_recent = fields.iter().any(|(key, value)| {
+            key.eq_ignore_ascii_case("MostRecent")
+                && matches!(value, VdfValue::Str(v) if v == "1")
+        });
+        if is_most_recent {
+            return steam_id_64.parse().ok();
+        }
+    }
+    None
+}
+
+fn steam64_to_account_id(steam_id_64: u64) -> u32 {
+    (steam_id_64 & 0xffff_ffff) as u32
+}
+
+// Locates the Steam install root, assumed to live under `Program Files (x86)/Steam` on the same
+// drive as the OS's standard local-data directory.
+fn find_steam_root() -> anyhow::Result<PathBuf> {
+    let local_data_dir = dirs::data_local_dir().ok_or_else(|| anyhow::Error::new(io::Error::new(
+        io::ErrorKind::NotFound,
+        "could not determine OS local-data directory",
+    )))?;
+    let drive_root = local_data_dir.ancestors().last().expect(
+        "local data dir should have a root ancestor"
+    ).to_path_buf();
+    let steam_root = drive_root.join("Program Files (x86)").join("Steam");
+    if !steam_root.exists() {
+        return Err(anyhow::Error::new(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("Steam root not found at {:?}", steam_root),
+        )));
+    }
+    Ok(steam_root)
+}
+
+// Reads the most recently used account's 32-bit account id out of Steam's `loginusers.vdf`, if
+// one can be determined.
+fn find_most_recent_steam_account_id() -> anyhow::Result<Option<u32>> {
+    let steam_root = find_steam_root().context(
+        "Failed to find most recent steam account id"
     )?;
-    let mut profile_dirs = Vec::new();
-    for user_id_dir in user_id_dirs {
-        let mut profiles_dir = user_id_dir;
-        profiles_dir.push("profiles");
-        if profiles_dir.exists() {
-            profile_dirs.push(profiles_dir)
-        } else {
-            return Err(anyhow::Error::new(io::Error::new(
-                io::ErrorKind::NotFound,
-                format!("Profiles dir not found at {}", profiles_dir.to_str().expect(
-                    "dir path should be a valid string"
-                )),
-            )));
+    let vdf_path = steam_root.join("config").join("loginusers.vdf");
+    let contents = fs::read_to_string(&vdf_path).context(
+        format!("Failed to read {:?}", vdf_path)
+    )?;
+    Ok(most_recent_steam_id_from_vdf(&contents).map(steam64_to_account_id))
+}
+
+// Resolves ambiguity between multiple user id dirs (e.g. the game was owned on both Steam and
+// Epic) by preferring the one matching the most recently used Steam account. Falls back to an
+// interactive choice when that lookup is ambiguous or unavailable.
+fn resolve_user_id_dir(mut user_id_dirs: Vec<PathBuf>) -> anyhow::Result<PathBuf> {
+    assert!(
+        user_id_dirs.len() > 0,
+        "if finding find_user_id_dirs didn't return err should have at least 1 dir",
+    );
+    if user_id_dirs.len() == 1 {
+        return Ok(user_id_dirs.swap_remove(0));
+    }
+
+    if let Ok(Some(account_id)) = find_most_recent_steam_account_id() {
+        let matching = user_id_dirs.iter().position(|dir| {
+            dir.file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| name.parse::<u32>().ok())
+                == Some(account_id)
+        });
+        if let Some(index) = matching {
+            return Ok(user_id_dirs.swap_remove(index));
         }
     }
-    Ok(profile_dirs)
+
+    println!("Found {} user id dirs, please choose one:", user_id_dirs.len());
+    for (i, dir) in user_id_dirs.iter().enumerate() {
+        println!("  [{}] {:?}", i, dir);
+    }
+    let mut selection = String::new();
+    io::stdin().read_line(&mut selection).context(
+        "Failed to read user id dir selection"
+    )?;
+    let index: usize = selection.trim().parse().context(
+        format!("Failed to parse user id dir selection {:?}", selection.trim())
+    )?;
+    if index >= user_id_dirs.len() {
+        return Err(anyhow::Error::new(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("selection {} out of range", index),
+        )));
+    }
+    Ok(user_id_dirs.swap_remove(index))
 }
 
 struct ScummedProfile {
@@ -130,6 +474,10 @@ struct ScummedProfile {
 }
 
 impl ScummedProfile {
+    // The naming scheme for backup dirs under `ensure_scumm_dir()`, shared with `backup_timestamp`
+    // so pruning can parse the time back out of a backup dir's name.
+    const BACKUP_DIR_TIMESTAMP_FORMAT: &'static str = "%Y-%m-%dT%H-%M-%S.%f";
+
     fn scumm_profile(
         profile_dir: &Path,
         scumm_dir: &Path,
@@ -145,9 +493,19 @@ impl ScummedProfile {
 
         let now = chrono::Utc::now();
         let mut dest_path = scumm_dir.to_path_buf();
-        dest_path.push(now.format("%Y-%m-%dT%H-%M-%S.%f").to_string());
+        dest_path.push(now.format(Self::BACKUP_DIR_TIMESTAMP_FORMAT).to_string());
 
-        copy_dir_recursively(profile_dir, &dest_path)?;
+        let options = CopyOptions::default();
+        if options.skip_identical {
+            if let Some(most_recent) = list_scummed_backups(scumm_dir).ok().and_then(
+                |backups| backups.into_iter().next()
+            ) {
+                hard_link_dir_recursively(&most_recent, &dest_path).context(
+                    format!("Failed to seed backup from {:?}", most_recent)
+                )?;
+            }
+        }
+        copy_dir_recursively(profile_dir, &dest_path, &options)?;
 
         Ok(ScummedProfile{
             source_path: profile_dir.to_path_buf(),
@@ -157,43 +515,583 @@ impl ScummedProfile {
     }
 }
 
-fn main() {
-    let profile_dirs = find_profiles_dirs();
-    let profile_dir = match profile_dirs {
+// Lists the timestamped backup dirs directly under `scumm_dir`, most recent first.
+fn list_scummed_backups(scumm_dir: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    let read_dir = fs::read_dir(scumm_dir).context(
+        format!("Failed to read scumm dir at {:?}", scumm_dir)
+    )?;
+    let mut backups = Vec::new();
+    for entry in read_dir {
+        let entry = entry.context("Failed to read entry while listing scummed backups")?;
+        if entry.file_type()?.is_dir() {
+            backups.push(entry.path());
+        }
+    }
+    backups.sort();
+    backups.reverse();
+    Ok(backups)
+}
+
+// Parses the time a backup dir was created back out of its name, per
+// `ScummedProfile::BACKUP_DIR_TIMESTAMP_FORMAT`.
+fn backup_timestamp(backup_dir: &Path) -> Option<chrono::DateTime<Utc>> {
+    let name = backup_dir.file_name()?.to_str()?;
+    let naive = chrono::NaiveDateTime::parse_from_str(
+        name, ScummedProfile::BACKUP_DIR_TIMESTAMP_FORMAT
+    ).ok()?;
+    Some(chrono::DateTime::from_naive_utc_and_offset(naive, Utc))
+}
+
+// The (device, inode) pair identifying a file's underlying storage, used to avoid double-counting
+// a file that's hard-linked across several backups (see `hard_link_dir_recursively`). `None` on
+// platforms where we can't determine this, in which case every file is counted as its own.
+#[cfg(unix)]
+fn inode_key(metadata: &fs::Metadata) -> Option<(u64, u64)> {
+    use std::os::unix::fs::MetadataExt;
+    Some((metadata.dev(), metadata.ino()))
+}
+
+#[cfg(windows)]
+fn inode_key(metadata: &fs::Metadata) -> Option<(u64, u64)> {
+    use std::os::windows::fs::MetadataExt;
+    Some((metadata.volume_serial_number()? as u64, metadata.file_index()?))
+}
+
+#[cfg(not(any(unix, windows)))]
+fn inode_key(_metadata: &fs::Metadata) -> Option<(u64, u64)> {
+    None
+}
+
+// A summary of a backup dir's on-disk footprint, surfaced in listings so users can judge what
+// pruning would free up.
+struct DirInfo {
+    path: PathBuf,
+    total_size: u64,
+    file_count: u64,
+}
+
+impl DirInfo {
+    // `seen_inodes` is shared across every backup in a listing: a file hard-linked from an older
+    // backup (see `hard_link_dir_recursively`) is only charged to the first backup that references
+    // it, since pruning every *other* backup referencing it wouldn't free its bytes.
+    fn compute(
+        dir: &Path,
+        seen_inodes: &mut std::collections::HashSet<(u64, u64)>,
+    ) -> anyhow::Result<DirInfo> {
+        let mut total_size = 0;
+        let mut file_count = 0;
+        let mut stack = vec![dir.to_path_buf()];
+        while let Some(current) = stack.pop() {
+            for entry in fs::read_dir(&current).context(
+                format!("Failed to read dir {:?} while computing size", current)
+            )? {
+                let entry = entry?;
+                if entry.file_type()?.is_dir() {
+                    stack.push(entry.path());
+                } else {
+                    let metadata = entry.metadata()?;
+                    let already_counted = inode_key(&metadata).is_some_and(
+                        |key| !seen_inodes.insert(key)
+                    );
+                    if !already_counted {
+                        total_size += metadata.len();
+                    }
+                    file_count += 1;
+                }
+            }
+        }
+        Ok(DirInfo { path: dir.to_path_buf(), total_size, file_count })
+    }
+}
+
+// Deletes backup dirs beyond `keep_last` most recent and/or older than `keep_within`, returning
+// the dirs that were removed (with `dry_run`, the dirs that would be, none being deleted). `None` for either bound disables that criterion; a backup is kept
+// if it satisfies at least one enabled bound.
+fn prune_backups(
+    scumm_dir: &Path,
+    keep_last: Option<usize>,
+    keep_within: Option<chrono::Duration>,
+    dry_run: bool,
+) -> anyhow::Result<Vec<PathBuf>> {
+    if keep_last.is_none() && keep_within.is_none() {
+        // No retention criteria given; nothing to prune.
+        return Ok(Vec::new());
+    }
+
+    let backups = list_scummed_backups(scumm_dir).context(
+        "Failed to list scummed backups while pruning"
+    )?;
+    let now = chrono::Utc::now();
+
+    let mut pruned = Vec::new();
+    for (i, backup) in backups.iter().enumerate() {
+        let within_count = keep_last.is_some_and(|keep_last| i < keep_last);
+        let within_age = keep_within.is_some_and(|keep_within| {
+            backup_timestamp(backup).is_some_and(|timestamp| now - timestamp <= keep_within)
+        });
+        if !within_count && !within_age {
+            if !dry_run {
+                fs::remove_dir_all(backup).context(
+                    format!("Failed to remove pruned backup dir {:?}", backup)
+                )?;
+            }
+            pruned.push(backup.clone());
+        }
+    }
+    Ok(pruned)
+}
+
+// Rolls a previously scummed backup back into the live profile. The current live profile is
+// scummed first so the restore itself is undoable. `scumm_dir` is the caller's already-resolved
+// scumm dir (honoring `--app-dir`/`DD2_SCUMMER_APP_DIR`), not re-derived here, so the pre-restore
+// backup lands in the same place as every other backup.
+fn restore_profile(backup_dir: &Path, profile_dir: &Path, scumm_dir: &Path) -> anyhow::Result<()> {
+    if !backup_dir.exists() {
+        return Err(anyhow::Error::new(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("Backup dir not found at {:?}", backup_dir),
+        )));
+    }
+
+    ScummedProfile::scumm_profile(profile_dir, scumm_dir)
+        .context("Failed to back up live profile before restoring")?;
+
+    if profile_dir.exists() {
+        fs::remove_dir_all(profile_dir).context(
+            format!("Failed to clear live profile dir at {:?}", profile_dir)
+        )?;
+    }
+    copy_dir_recursively(backup_dir, profile_dir, &CopyOptions::default()).context(
+        format!("Failed to copy backup from {:?} to {:?}", backup_dir, profile_dir)
+    )?;
+
+    Ok(())
+}
+
+// Prints each backup alongside its `DirInfo` summary, or just the path if the summary can't be
+// computed. Sizes are attributed across the whole listing (see `DirInfo::compute`), so a file
+// shared by hard link between backups only counts toward the first-listed (most recent) of them.
+fn print_backup_listing(backups: &[PathBuf]) {
+    let mut seen_inodes = std::collections::HashSet::new();
+    for (i, backup) in backups.iter().enumerate() {
+        match DirInfo::compute(backup, &mut seen_inodes) {
+            Err(_) => println!("  [{}] {:?}", i, backup),
+            Ok(info) => println!(
+                "  [{}] {:?} ({} files, {} bytes)", i, info.path, info.file_count, info.total_size
+            ),
+        }
+    }
+}
+
+// Parses simple durations of the form `<number><unit>` where unit is one of `s`, `m`, `h`, `d`
+// (e.g. `"7d"`, `"12h"`), as used by `--keep-within`.
+fn parse_duration(s: &str) -> anyhow::Result<chrono::Duration> {
+    let s = s.trim();
+    let split_at = s.find(|c: char| !c.is_ascii_digit()).ok_or_else(
+        || anyhow::anyhow!("duration {:?} is missing a unit suffix (s/m/h/d)", s)
+    )?;
+    let (amount, unit) = s.split_at(split_at);
+    let amount: i64 = amount.parse().context(format!("invalid duration amount in {:?}", s))?;
+    match unit {
+        "s" => Ok(chrono::Duration::seconds(amount)),
+        "m" => Ok(chrono::Duration::minutes(amount)),
+        "h" => Ok(chrono::Duration::hours(amount)),
+        "d" => Ok(chrono::Duration::days(amount)),
+        other => Err(anyhow::anyhow!("unknown duration unit {:?} in {:?}", other, s)),
+    }
+}
+
+fn run_prune(
+    scumm_dir: &Path,
+    keep_last: Option<usize>,
+    keep_within: Option<chrono::Duration>,
+    dry_run: bool,
+) {
+    match prune_backups(scumm_dir, keep_last, keep_within, dry_run) {
+        Err(e) => println!("failed to prune backups: {e}"),
+        Ok(pruned) if pruned.is_empty() => println!("no backups needed pruning"),
+        Ok(pruned) => {
+            println!("{} {} backup(s):", if dry_run { "would prune" } else { "pruned" }, pruned.len());
+            for backup in pruned {
+                println!("  {:?}", backup);
+            }
+        },
+    }
+}
+
+fn run_restore(profile_dir: &Path, scumm_dir: &Path, interactive: bool, dry_run: bool) {
+    let backups = match list_scummed_backups(scumm_dir) {
         Err(e) => {
-            println!("Failed to find profile dirs: {e}");
+            println!("failed to list scummed backups: {e}");
             return;
         },
-        Ok(mut dirs) => {
-            assert!(
-                dirs.len() > 0,
-                "if finding find_profiles_dirs didn't return err should have at least 1 dir",
-            );
-            if dirs.len() > 1 {
-                println!("Found {} profile dirs, but currently only support 1 dir", dirs.len());
-                return;
-            }
-            dirs.swap_remove(0)
-        }
+        Ok(backups) => backups,
     };
+    if backups.is_empty() {
+        println!("no scummed backups found in {:?}", scumm_dir);
+        return;
+    }
 
-    let scumm_dir = match ensure_scumm_dir() {
+    println!("available backups:");
+    print_backup_listing(&backups);
+    println!("select a backup to restore by index:");
+
+    let mut selection = String::new();
+    if let Err(e) = io::stdin().read_line(&mut selection) {
+        println!("failed to read selection: {e}");
+        return;
+    }
+    let index: usize = match selection.trim().parse() {
         Err(e) => {
-            println!("failed to ensure scumm dir: {e}");
+            println!("failed to parse selection {:?}: {e}", selection.trim());
             return;
         },
-        Ok(dir) => dir,
+        Ok(index) => index,
+    };
+    let backup = match backups.get(index) {
+        None => {
+            println!("selection {} out of range", index);
+            return;
+        },
+        Some(backup) => backup,
     };
 
-    match ScummedProfile::scumm_profile(&profile_dir, &scumm_dir) {
+    if dry_run {
+        println!("dry run: would overwrite the live profile at {:?} with {:?}", profile_dir, backup);
+        return;
+    }
+
+    if interactive && !confirm(
+        &format!("This will overwrite the live profile at {:?} with {:?}. Continue?", profile_dir, backup)
+    ) {
+        println!("restore cancelled");
+        return;
+    }
+
+    match restore_profile(backup, profile_dir, scumm_dir) {
+        Err(e) => println!("failed to restore profile: {e}"),
+        Ok(()) => println!("successfully restored {:?} to {:?}", backup, profile_dir),
+    }
+}
+
+fn run_list(scumm_dir: &Path) {
+    match list_scummed_backups(scumm_dir) {
+        Err(e) => println!("failed to list scummed backups: {e}"),
+        Ok(backups) if backups.is_empty() => println!("no scummed backups found in {:?}", scumm_dir),
+        Ok(backups) => print_backup_listing(&backups),
+    }
+}
+
+// Prompts `{prompt} [y/N]` on stdin, returning true only for an explicit yes.
+fn confirm(prompt: &str) -> bool {
+    println!("{} [y/N]", prompt);
+    let mut response = String::new();
+    if io::stdin().read_line(&mut response).is_err() {
+        return false;
+    }
+    matches!(response.trim().to_lowercase().as_str(), "y" | "yes")
+}
+
+// Whether any cause in `e`'s chain is an `io::ErrorKind::NotFound`, i.e. the kind of "nothing here yet"
+// error `--force` is meant to suppress.
+fn is_not_found(e: &anyhow::Error) -> bool {
+    e.chain().any(|cause| {
+        cause.downcast_ref::<io::Error>().is_some_and(|io_err| io_err.kind() == io::ErrorKind::NotFound)
+    })
+}
+
+/// A save-scummer for Darkest Dungeon II: back up, list, restore, and prune save profiles.
+#[derive(clap::Parser)]
+#[command(name = "dd2_scummer")]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Suppress "not found" style errors instead of reporting them (e.g. when there's nothing
+    /// to scum or restore yet).
+    #[arg(long, global = true)]
+    force: bool,
+
+    /// Prompt for a yes/no confirmation before destructive operations such as restore.
+    #[arg(long, global = true)]
+    interactive: bool,
+
+    /// Override the detected Darkest Dungeon II app data dir.
+    #[arg(long, global = true)]
+    app_dir: Option<PathBuf>,
+
+    /// Report what a destructive operation (restore, prune) would do without changing anything
+    /// on disk.
+    #[arg(long, global = true)]
+    dry_run: bool,
+}
+
+#[derive(clap::Subcommand)]
+enum Command {
+    /// Back up the live profile into a new timestamped backup (the default when no subcommand
+    /// is given).
+    Scum {
+        #[arg(long)]
+        keep_last: Option<usize>,
+        #[arg(long)]
+        keep_within: Option<String>,
+    },
+    /// Roll a previous backup back into the live profile.
+    Restore,
+    /// List available backups.
+    List,
+    /// Delete old backups per a retention policy.
+    Prune {
+        #[arg(long)]
+        keep_last: Option<usize>,
+        #[arg(long)]
+        keep_within: Option<String>,
+    },
+}
+
+fn parse_keep_within(keep_within: &Option<String>) -> anyhow::Result<Option<chrono::Duration>> {
+    keep_within.as_deref().map(parse_duration).transpose()
+}
+
+// Resolves the live profile dir, honoring `--app-dir`/`DD2_SCUMMER_APP_DIR`. Only needed by the
+// `Scum`/`Restore` arms of `main` — `List`/`Prune` only ever touch `scumm_dir`.
+fn resolve_profile_dir(app_dir_override: Option<&Path>) -> anyhow::Result<PathBuf> {
+    let user_id_dir = find_user_id_dirs(app_dir_override).and_then(resolve_user_id_dir)?;
+    profile_dir_from_user_id_dir(&user_id_dir)
+}
+
+fn main() {
+    let cli = <Cli as clap::Parser>::parse();
+
+    let scumm_dir = match ensure_scumm_dir(cli.app_dir.as_deref()) {
+        Err(e) if cli.force && is_not_found(&e) => return,
         Err(e) => {
-            println!("failed to scumm profile: {e}");
+            println!("failed to ensure scumm dir: {e}");
             return;
         },
-        Ok(scummed) => println!(
-            "successfully scummed current profile from {:?} to {:?}",
-            scummed.source_path,
-            scummed.dest_path,
-        ),
+        Ok(dir) => dir,
+    };
+
+    match cli.command.unwrap_or(Command::Scum { keep_last: None, keep_within: None }) {
+        Command::Restore => {
+            let profile_dir = match resolve_profile_dir(cli.app_dir.as_deref()) {
+                Err(e) if cli.force && is_not_found(&e) => return,
+                Err(e) => {
+                    println!("Failed to find profile dir: {e}");
+                    return;
+                },
+                Ok(dir) => dir,
+            };
+            run_restore(&profile_dir, &scumm_dir, cli.interactive, cli.dry_run);
+        },
+        Command::List => run_list(&scumm_dir),
+        Command::Prune { keep_last, keep_within } => {
+            let keep_within = match parse_keep_within(&keep_within) {
+                Err(e) => {
+                    println!("invalid --keep-within value: {e}");
+                    return;
+                },
+                Ok(keep_within) => keep_within,
+            };
+            if !cli.dry_run && cli.interactive
+                && !confirm("This will permanently delete pruned backups. Continue?")
+            {
+                println!("prune cancelled");
+                return;
+            }
+            run_prune(&scumm_dir, keep_last, keep_within, cli.dry_run);
+        },
+        Command::Scum { keep_last, keep_within } => {
+            let profile_dir = match resolve_profile_dir(cli.app_dir.as_deref()) {
+                Err(e) if cli.force && is_not_found(&e) => return,
+                Err(e) => {
+                    println!("Failed to find profile dir: {e}");
+                    return;
+                },
+                Ok(dir) => dir,
+            };
+            let keep_within = match parse_keep_within(&keep_within) {
+                Err(e) => {
+                    println!("invalid --keep-within value: {e}");
+                    return;
+                },
+                Ok(keep_within) => keep_within,
+            };
+
+            match ScummedProfile::scumm_profile(&profile_dir, &scumm_dir) {
+                Err(e) => {
+                    println!("failed to scumm profile: {e}");
+                    return;
+                },
+                Ok(scummed) => println!(
+                    "successfully scummed current profile from {:?} to {:?}",
+                    scummed.source_path,
+                    scummed.dest_path,
+                ),
+            }
+
+            if keep_last.is_some() || keep_within.is_some() {
+                run_prune(&scumm_dir, keep_last, keep_within, cli.dry_run);
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A scratch dir under the OS temp dir, removed on drop, used by tests that exercise the
+    // actual filesystem-mutating logic (hard-linking, copying, pruning).
+    struct TempDir {
+        path: PathBuf,
+    }
+
+    impl TempDir {
+        fn new(label: &str) -> Self {
+            use std::sync::atomic::{AtomicU64, Ordering};
+            static COUNTER: AtomicU64 = AtomicU64::new(0);
+            let id = COUNTER.fetch_add(1, Ordering::Relaxed);
+            let path = std::env::temp_dir().join(
+                format!("dd2_scummer_test_{}_{}_{}", std::process::id(), label, id)
+            );
+            fs::create_dir_all(&path).expect("failed to create temp test dir");
+            TempDir { path }
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.path);
+        }
+    }
+
+    #[test]
+    fn most_recent_steam_id_from_vdf_picks_flagged_account() {
+        let vdf = r#"
+"users"
+{
+    "76561197960287930"
+    {
+        "AccountName"       "alice"
+        "MostRecent"        "0"
+    }
+    "76561197960265788"
+    {
+        "AccountName"       "bob"
+        "MostRecent"        "1"
+    }
+}
+"#;
+        assert_eq!(most_recent_steam_id_from_vdf(vdf), Some(76561197960265788));
+    }
+
+    #[test]
+    fn most_recent_steam_id_from_vdf_returns_none_without_a_flagged_account() {
+        let vdf = r#"
+"users"
+{
+    "76561197960287930"
+    {
+        "AccountName"       "alice"
+        "MostRecent"        "0"
+    }
+}
+"#;
+        assert_eq!(most_recent_steam_id_from_vdf(vdf), None);
+    }
+
+    #[test]
+    fn steam64_to_account_id_masks_to_the_lower_32_bits() {
+        assert_eq!(steam64_to_account_id(76561197960265788), 60);
+    }
+
+    #[test]
+    fn parse_duration_accepts_each_supported_unit() {
+        assert_eq!(parse_duration("7d").unwrap(), chrono::Duration::days(7));
+        assert_eq!(parse_duration("12h").unwrap(), chrono::Duration::hours(12));
+        assert_eq!(parse_duration("30m").unwrap(), chrono::Duration::minutes(30));
+        assert_eq!(parse_duration("45s").unwrap(), chrono::Duration::seconds(45));
+    }
+
+    #[test]
+    fn parse_duration_rejects_a_missing_unit() {
+        assert!(parse_duration("7").is_err());
+    }
+
+    #[test]
+    fn parse_duration_rejects_an_unknown_unit() {
+        assert!(parse_duration("7y").is_err());
+    }
+
+    // Regression test for the bug fixed in `remove_stale_dst_entries`: a file deleted from the
+    // live profile between two scums must not keep resurrecting in every backup seeded from the
+    // previous one via `hard_link_dir_recursively`.
+    #[test]
+    fn scumming_removes_files_deleted_since_the_previous_backup() {
+        let root = TempDir::new("scumm_stale_entry");
+        let profile_dir = root.path.join("profile");
+        let scumm_dir = root.path.join("scummed");
+        fs::create_dir_all(&profile_dir).unwrap();
+        fs::create_dir_all(&scumm_dir).unwrap();
+        fs::write(profile_dir.join("a.txt"), b"keep me").unwrap();
+        fs::write(profile_dir.join("b.txt"), b"delete me").unwrap();
+
+        ScummedProfile::scumm_profile(&profile_dir, &scumm_dir)
+            .expect("first scum should succeed");
+
+        fs::remove_file(profile_dir.join("b.txt")).unwrap();
+        // Ensure the second backup gets a distinct, later timestamp-named dir than the first.
+        std::thread::sleep(std::time::Duration::from_millis(10));
+
+        ScummedProfile::scumm_profile(&profile_dir, &scumm_dir)
+            .expect("second scum should succeed");
+
+        let backups = list_scummed_backups(&scumm_dir).expect("should list backups");
+        assert_eq!(backups.len(), 2, "expected exactly two backups");
+        let (latest, previous) = (&backups[0], &backups[1]);
+
+        assert!(latest.join("a.txt").exists());
+        assert!(
+            !latest.join("b.txt").exists(),
+            "b.txt was deleted from the live profile and should not be resurrected in the new backup"
+        );
+        // The older backup is untouched history and should still reflect what was live at the time.
+        assert!(previous.join("a.txt").exists());
+        assert!(previous.join("b.txt").exists());
+    }
+
+    #[test]
+    fn prune_backups_keeps_a_backup_satisfying_either_bound() {
+        let root = TempDir::new("prune_combined_bounds");
+        let scumm_dir = root.path.clone();
+        let now = chrono::Utc::now();
+        // Five backups, one per day old, newest first.
+        let backup_dirs: Vec<PathBuf> = (0..5).map(|days_old| {
+            let name = (now - chrono::Duration::days(days_old))
+                .format(ScummedProfile::BACKUP_DIR_TIMESTAMP_FORMAT)
+                .to_string();
+            let dir = scumm_dir.join(name);
+            fs::create_dir_all(&dir).unwrap();
+            dir
+        }).collect();
+
+        // keep_last=1 keeps only the newest; keep_within=36h additionally keeps anything younger
+        // than that, which includes the 1-day-old one that keep_last alone would prune, so only
+        // the 2/3/4-day-old backups should actually be pruned.
+        let pruned = prune_backups(&scumm_dir, Some(1), Some(chrono::Duration::hours(36)), false)
+            .expect("prune should succeed");
+
+        let pruned_names: std::collections::HashSet<_> = pruned.iter().cloned().collect();
+        assert_eq!(pruned_names.len(), 3);
+        assert!(pruned_names.contains(&backup_dirs[2]));
+        assert!(pruned_names.contains(&backup_dirs[3]));
+        assert!(pruned_names.contains(&backup_dirs[4]));
+
+        assert!(backup_dirs[0].exists(), "newest backup should be kept");
+        assert!(backup_dirs[1].exists(), "backup within keep_within should be kept");
+        assert!(!backup_dirs[2].exists());
+        assert!(!backup_dirs[3].exists());
+        assert!(!backup_dirs[4].exists());
     }
 }